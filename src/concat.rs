@@ -0,0 +1,83 @@
+use crate::EnvError;
+use std::env;
+use std::env::VarError;
+
+enum Fragment {
+  Literal(String),
+  Var {
+    env_name: String,
+    default: Option<String>,
+  },
+}
+
+/// Build a single `String` by assembling literal fragments and environment variable lookups, in
+/// order. Useful for deriving a bind address or connection URL from separately-configured
+/// host/port pieces, e.g.:
+///
+/// ```ignore
+/// let addr = EnvConcat::new()
+///   .var("ADDR", "127.0.0.1")
+///   .literal(":")
+///   .var("PORT", "8000")
+///   .build()?;
+/// ```
+#[derive(Default)]
+pub struct EnvConcat {
+  fragments: Vec<Fragment>,
+}
+
+impl EnvConcat {
+  /// Start a new, empty concatenation.
+  pub fn new() -> EnvConcat {
+    EnvConcat { fragments: Vec::new() }
+  }
+
+  /// Append a literal fragment, used as-is.
+  pub fn literal(mut self, value: &str) -> EnvConcat {
+    self.fragments.push(Fragment::Literal(value.to_string()));
+    self
+  }
+
+  /// Append an environment variable lookup, falling back to `default` if the variable isn't
+  /// present.
+  pub fn var(mut self, env_name: &str, default: &str) -> EnvConcat {
+    self.fragments.push(Fragment::Var {
+      env_name: env_name.to_string(),
+      default: Some(default.to_string()),
+    });
+    self
+  }
+
+  /// Append a required environment variable lookup with no default. If the variable is absent,
+  /// `build()` returns `EnvError::RequiredNotPresent`.
+  pub fn required_var(mut self, env_name: &str) -> EnvConcat {
+    self.fragments.push(Fragment::Var {
+      env_name: env_name.to_string(),
+      default: None,
+    });
+    self
+  }
+
+  /// Assemble the final `String` from all fragments, in the order they were added.
+  /// A present-but-invalid-unicode variable surfaces `EnvError::NotUnicode`; a missing variable
+  /// with no default surfaces `EnvError::RequiredNotPresent`.
+  pub fn build(self) -> Result<String, EnvError> {
+    let mut result = String::new();
+
+    for fragment in self.fragments {
+      match fragment {
+        Fragment::Literal(value) => result.push_str(&value),
+        Fragment::Var { env_name, default } => match env::var(&env_name) {
+          Ok(val) => result.push_str(&val),
+          Err(VarError::NotUnicode(_)) => return Err(EnvError::NotUnicode),
+          Err(VarError::NotPresent) => match default {
+            Some(default) => result.push_str(&default),
+            None => return Err(EnvError::RequiredNotPresent),
+          },
+        },
+      }
+    }
+
+    Ok(result)
+  }
+}
@@ -0,0 +1,163 @@
+//! Declarative `config!` macro for describing a whole environment schema in one place.
+//!
+//! `config!` expands into a `config` module of typed accessors, one function per declared
+//! variable, backed by the plain `get_env_*` functions this crate already exposes elsewhere.
+//! It exists purely to remove the boilerplate of hand-writing one accessor function per
+//! variable; it doesn't introduce any parsing behavior the rest of the crate doesn't already
+//! have.
+
+/// Declare a typed configuration schema backed by the crate's `get_env_*` helpers.
+///
+/// ```ignore
+/// config! {
+///   DATABASE_URL: String,
+///   PORT: u16 => 8080,
+///   DEBUG: bool,
+///   namespace db {
+///     POOL_SIZE: u32 => 10,
+///   }
+/// }
+///
+/// let port = config::PORT();
+/// let pool_size = config::db::POOL_SIZE();
+/// ```
+///
+/// A bare `NAME: Type` entry is required: the generated accessor panics if the variable is
+/// missing or can't be parsed. A `NAME: Type => default` entry falls back to `default` if the
+/// variable is missing or can't be parsed; it never panics. A `namespace NAME {
+/// ... }` block nests a submodule whose variables are prefixed with `NAME_` (uppercased).
+///
+/// `bool` fields go through `get_env_bool_required`/`get_env_bool_or_default`, so they accept
+/// this crate's usual `TRUE`/`FALSE` spellings in addition to `true`/`false`. Every other type
+/// goes through the generic `get_env_required`/`get_env_or_default` helpers, so `default` must
+/// be a value of the declared type, not something merely convertible to it (e.g. a `String`
+/// field needs `=> "x".to_string()`, not `=> "x"`).
+///
+/// The generated module also gets an `init()` function that eagerly calls every accessor
+/// (including those in nested namespaces), so a misconfigured environment fails fast at
+/// startup instead of on first access.
+#[macro_export]
+macro_rules! config {
+  ( $( $body:tt )* ) => {
+    /// Generated by the `config!` macro: typed accessors for the declared environment schema.
+    pub mod config {
+      #![allow(non_snake_case, missing_docs)]
+
+      $crate::__config_items!( "" ; $( $body )* );
+
+      /// Eagerly read and validate every configuration value declared in this module,
+      /// including nested namespaces, so misconfiguration fails fast at startup.
+      pub fn init() {
+        $crate::__config_init!( $( $body )* );
+      }
+    }
+  };
+}
+
+/// Implementation detail of `config!`. Not part of the public API.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __config_items {
+  ( $prefix:expr ; ) => {};
+
+  // `bool` is special-cased ahead of the generic arms below so it goes through this crate's
+  // bespoke `get_env_bool_*` helpers (which accept `TRUE`/`FALSE`) instead of std's `FromStr`.
+  ( $prefix:expr ; $name:ident : bool => $default:expr ) => {
+    $crate::__config_items!( $prefix ; $name : bool => $default , );
+  };
+  ( $prefix:expr ; $name:ident : bool => $default:expr , $( $rest:tt )* ) => {
+    #[allow(non_snake_case)]
+    pub fn $name() -> bool {
+      let env_name = format!("{}{}", $prefix, stringify!($name));
+      $crate::get_env_bool_or_default(&env_name, $default)
+    }
+    $crate::__config_items!( $prefix ; $( $rest )* );
+  };
+
+  ( $prefix:expr ; $name:ident : bool ) => {
+    $crate::__config_items!( $prefix ; $name : bool , );
+  };
+  ( $prefix:expr ; $name:ident : bool , $( $rest:tt )* ) => {
+    #[allow(non_snake_case)]
+    pub fn $name() -> bool {
+      let env_name = format!("{}{}", $prefix, stringify!($name));
+      $crate::get_env_bool_required(&env_name)
+        .unwrap_or_else(|e| panic!("Invalid config value for '{}': {:?}", env_name, e))
+    }
+    $crate::__config_items!( $prefix ; $( $rest )* );
+  };
+
+  ( $prefix:expr ; $name:ident : $ty:ty => $default:expr ) => {
+    $crate::__config_items!( $prefix ; $name : $ty => $default , );
+  };
+  ( $prefix:expr ; $name:ident : $ty:ty => $default:expr , $( $rest:tt )* ) => {
+    #[allow(non_snake_case)]
+    pub fn $name() -> $ty {
+      let env_name = format!("{}{}", $prefix, stringify!($name));
+      $crate::get_env_or_default::<$ty>(&env_name, $default)
+    }
+    $crate::__config_items!( $prefix ; $( $rest )* );
+  };
+
+  ( $prefix:expr ; $name:ident : $ty:ty ) => {
+    $crate::__config_items!( $prefix ; $name : $ty , );
+  };
+  ( $prefix:expr ; $name:ident : $ty:ty , $( $rest:tt )* ) => {
+    #[allow(non_snake_case)]
+    pub fn $name() -> $ty {
+      let env_name = format!("{}{}", $prefix, stringify!($name));
+      $crate::get_env_required::<$ty>(&env_name)
+        .unwrap_or_else(|e| panic!("Invalid config value for '{}': {:?}", env_name, e))
+    }
+    $crate::__config_items!( $prefix ; $( $rest )* );
+  };
+
+  ( $prefix:expr ; namespace $ns:ident { $( $inner:tt )* } ) => {
+    $crate::__config_items!( $prefix ; namespace $ns { $( $inner )* } , );
+  };
+  ( $prefix:expr ; namespace $ns:ident { $( $inner:tt )* } , $( $rest:tt )* ) => {
+    /// Generated by the `config!` macro: typed accessors for this namespace.
+    pub mod $ns {
+      #![allow(non_snake_case, missing_docs)]
+
+      $crate::__config_items!( format!("{}{}_", $prefix, stringify!($ns).to_uppercase()) ; $( $inner )* );
+
+      /// Eagerly read and validate every configuration value declared in this namespace.
+      pub fn init() {
+        $crate::__config_init!( $( $inner )* );
+      }
+    }
+    $crate::__config_items!( $prefix ; $( $rest )* );
+  };
+}
+
+/// Implementation detail of `config!`. Not part of the public API.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __config_init {
+  ( ) => {};
+
+  ( $name:ident : $ty:ty => $default:expr ) => {
+    let _ = $name();
+  };
+  ( $name:ident : $ty:ty => $default:expr , $( $rest:tt )* ) => {
+    let _ = $name();
+    $crate::__config_init!( $( $rest )* );
+  };
+
+  ( $name:ident : $ty:ty ) => {
+    let _ = $name();
+  };
+  ( $name:ident : $ty:ty , $( $rest:tt )* ) => {
+    let _ = $name();
+    $crate::__config_init!( $( $rest )* );
+  };
+
+  ( namespace $ns:ident { $( $inner:tt )* } ) => {
+    $ns::init();
+  };
+  ( namespace $ns:ident { $( $inner:tt )* } , $( $rest:tt )* ) => {
+    $ns::init();
+    $crate::__config_init!( $( $rest )* );
+  };
+}
@@ -61,3 +61,141 @@ fn get_env_duration_seconds_internal(env_name: &str) -> Result<Option<Duration>,
     }
   }
 }
+
+/// Get an environment variable as a `Duration`, parsed from a human-readable string like
+/// `"30s"`, `"500ms"`, or `"1h30m"`. See `parse_human_duration` for the supported units.
+/// If not provided or cannot parse, return an error.
+pub fn get_env_duration_required(env_name: &str) -> Result<Duration, EnvError> {
+  get_env_duration_internal(env_name)
+    .and_then(|maybe| match maybe {
+      None => {
+        warn!("Env var '{}' not supplied.", env_name);
+        Err(EnvError::RequiredNotPresent)
+      },
+      Some(val) => Ok(val),
+    })
+}
+
+/// Get an environment variable as a `Duration`, parsed from a human-readable string.
+/// If not present or there is an error in parsing, return `None`.
+pub fn get_env_duration_optional(env_name: &str) -> Option<Duration> {
+  match get_env_duration_internal(env_name) {
+    Err(e) => {
+      warn!("Env var '{}': error parsing duration value: `{:?}`. Returning no value.", env_name, e);
+      None
+    },
+    Ok(None) => {
+      warn!("Env var '{}' not present.", env_name);
+      None
+    },
+    Ok(Some(value)) => Some(value),
+  }
+}
+
+/// Get an environment variable as a `Duration`, parsed from a human-readable string, or fall
+/// back to the provided default. Returns the default in the event of a parse error.
+pub fn get_env_duration_or_default(env_name: &str, default: Duration) -> Duration {
+  get_env_duration_internal(env_name)
+    .map(|maybe| match maybe {
+      None => {
+        warn!("Env var '{}' not supplied. Using default '{:?}'.", env_name, default);
+        default
+      },
+      Some(val) => val,
+    })
+    .unwrap_or_else(|e| {
+      warn!("Env var '{}': error parsing duration value: {:?}. Using default '{:?}'.",
+            env_name, e, default);
+      default
+    })
+}
+
+fn get_env_duration_internal(env_name: &str) -> Result<Option<Duration>, EnvError> {
+  match env::var(env_name).as_ref().ok() {
+    None => Ok(None),
+    Some(val) => parse_human_duration(val).map(Some),
+  }
+}
+
+/// Parse a human-readable duration string, e.g. `"30s"`, `"500ms"`, `"1h30m"`, or `"2d"`.
+///
+/// The string is tokenized into `(number, unit)` segments: consecutive digits (and an optional
+/// decimal point) are accumulated into a value, followed by a unit suffix from `ns`, `us`/`µs`,
+/// `ms`, `s`, `m`, `h`, `d`, or `w`. Each segment is converted to nanoseconds and summed. Returns
+/// `EnvError::ParseError` for an empty string, a number with no trailing unit, an unknown unit,
+/// or a total that overflows `u64` nanoseconds.
+fn parse_human_duration(val: &str) -> Result<Duration, EnvError> {
+  let mut chars = val.chars().peekable();
+  let mut total_nanos: u64 = 0;
+  let mut parsed_any_segment = false;
+
+  while chars.peek().is_some() {
+    let mut number = String::new();
+    while let Some(&c) = chars.peek() {
+      if c.is_ascii_digit() || c == '.' {
+        number.push(c);
+        chars.next();
+      } else {
+        break;
+      }
+    }
+    if number.is_empty() {
+      return Err(EnvError::ParseError {
+        reason: format!("Couldn't parse as duration: '{}'", val)
+      });
+    }
+
+    let mut unit = String::new();
+    while let Some(&c) = chars.peek() {
+      if c.is_alphabetic() {
+        unit.push(c);
+        chars.next();
+      } else {
+        break;
+      }
+    }
+    if unit.is_empty() {
+      return Err(EnvError::ParseError {
+        reason: format!("Couldn't parse as duration, missing unit after '{}': '{}'", number, val)
+      });
+    }
+
+    let value: f64 = number.parse().map_err(|_| EnvError::ParseError {
+      reason: format!("Couldn't parse as duration: '{}'", val)
+    })?;
+
+    let nanos_per_unit: u64 = match unit.as_str() {
+      "ns" => 1,
+      "us" | "µs" => 1_000,
+      "ms" => 1_000_000,
+      "s" => 1_000_000_000,
+      "m" => 60 * 1_000_000_000,
+      "h" => 60 * 60 * 1_000_000_000,
+      "d" => 24 * 60 * 60 * 1_000_000_000,
+      "w" => 7 * 24 * 60 * 60 * 1_000_000_000,
+      _ => return Err(EnvError::ParseError {
+        reason: format!("Couldn't parse as duration, unknown unit '{}': '{}'", unit, val)
+      }),
+    };
+
+    let segment_nanos = value * nanos_per_unit as f64;
+    if !segment_nanos.is_finite() || segment_nanos < 0.0 || segment_nanos > u64::MAX as f64 {
+      return Err(EnvError::ParseError {
+        reason: format!("Duration value overflows u64 nanoseconds: '{}'", val)
+      });
+    }
+
+    total_nanos = total_nanos.checked_add(segment_nanos as u64)
+      .ok_or_else(|| EnvError::ParseError {
+        reason: format!("Duration value overflows u64 nanoseconds: '{}'", val)
+      })?;
+
+    parsed_any_segment = true;
+  }
+
+  if !parsed_any_segment {
+    return Err(EnvError::ParseError { reason: "Couldn't parse as duration: empty string".to_string() });
+  }
+
+  Ok(Duration::from_nanos(total_nanos))
+}
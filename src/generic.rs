@@ -0,0 +1,76 @@
+use crate::EnvError;
+use log::warn;
+use std::any;
+use std::env;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// Get an environment variable, parsed as any `FromStr` type `T`.
+/// If not provided or cannot parse, return an error.
+pub fn get_env_required<T>(env_name: &str) -> Result<T, EnvError>
+  where T: FromStr,
+        T::Err: Debug
+{
+  get_env_internal(env_name)
+    .and_then(|maybe| match maybe {
+      None => {
+        warn!("Env var '{}' not supplied.", env_name);
+        Err(EnvError::RequiredNotPresent)
+      },
+      Some(val) => Ok(val),
+    })
+}
+
+/// Get an environment variable, parsed as any `FromStr` type `T`.
+/// If not present or there is an error in parsing, return `None`.
+pub fn get_env_optional<T>(env_name: &str) -> Option<T>
+  where T: FromStr,
+        T::Err: Debug
+{
+  match get_env_internal(env_name) {
+    Err(e) => {
+      warn!("Env var '{}': error parsing value: `{:?}`. Returning no value.", env_name, e);
+      None
+    },
+    Ok(None) => {
+      warn!("Env var '{}' not present.", env_name);
+      None
+    },
+    Ok(Some(value)) => Some(value),
+  }
+}
+
+/// Get an environment variable, parsed as any `FromStr` type `T`, or fall back to the provided
+/// default. Returns the default in the event of a parse error.
+pub fn get_env_or_default<T>(env_name: &str, default: T) -> T
+  where T: FromStr + Debug + Clone,
+        T::Err: Debug
+{
+  get_env_internal(env_name)
+    .map(|maybe| match maybe {
+      None => {
+        warn!("Env var '{}' not supplied. Using default '{:?}'.", env_name, &default);
+        default.clone() // FIXME: Remove this extra clone
+      },
+      Some(val) => val,
+    })
+    .unwrap_or_else(|e| {
+      warn!("Env var '{}': error parsing value: {:?}. Using default '{:?}'.",
+            env_name, e, &default);
+      default
+    })
+}
+
+pub(crate) fn get_env_internal<T>(env_name: &str) -> Result<Option<T>, EnvError>
+  where T: FromStr,
+        T::Err: Debug
+{
+  match env::var(env_name).as_ref().ok() {
+    None => Ok(None),
+    Some(val) => val.parse::<T>()
+      .map(Some)
+      .map_err(|e| EnvError::ParseError {
+        reason: format!("Couldn't parse '{}' as {}: {:?}", val, any::type_name::<T>(), e)
+      }),
+  }
+}
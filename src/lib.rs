@@ -17,10 +17,16 @@ use std::fmt::{Display, Debug, Formatter};
 use std::{env, fmt};
 
 mod boolean;
+mod concat;
+mod config_macro;
 mod duration;
+mod generic;
+mod log_config;
+mod os_string;
 mod pathbuf;
 mod string;
 mod num;
+mod vec;
 
 /// Name of the environment variable Rust's env logger uses
 pub const ENV_RUST_LOG : &'static str = "RUST_LOG";
@@ -57,20 +63,44 @@ pub use boolean::get_env_bool_optional;
 pub use boolean::get_env_bool_or_default;
 pub use boolean::get_env_bool_required;
 
+pub use concat::EnvConcat;
+
+pub use duration::get_env_duration_optional;
+pub use duration::get_env_duration_or_default;
+pub use duration::get_env_duration_required;
+
 pub use duration::get_env_duration_seconds_optional;
 pub use duration::get_env_duration_seconds_or_default;
 pub use duration::get_env_duration_seconds_required;
 
+pub use generic::get_env_optional;
+pub use generic::get_env_or_default;
+pub use generic::get_env_required;
+
+pub use log_config::LogConfig;
+
 pub use num::get_env_num;
 
+pub use os_string::get_env_os_string_optional;
+pub use os_string::get_env_os_string_or_default;
+pub use os_string::get_env_os_string_required;
+
 pub use pathbuf::get_env_pathbuf_optional;
 pub use pathbuf::get_env_pathbuf_or_default;
 pub use pathbuf::get_env_pathbuf_required;
 
+pub use pathbuf::get_env_pathbuf_os_optional;
+pub use pathbuf::get_env_pathbuf_os_or_default;
+pub use pathbuf::get_env_pathbuf_os_required;
+
 pub use string::get_env_string_optional;
 pub use string::get_env_string_or_default;
 pub use string::get_env_string_required;
 
+pub use vec::get_env_vec_optional;
+pub use vec::get_env_vec_or_default;
+pub use vec::get_env_vec_required;
+
 /// Initialize Rust's env logger.
 ///
 /// The Rust logger reads the desired log level from the `RUST_LOG` environment variable. If this
@@ -95,6 +125,32 @@ pub fn init_env_logger(default_if_absent: Option<&str>) {
   env_logger::init();
 }
 
+/// Initialize Rust's env logger from a `LogConfig`.
+///
+/// Like `init_env_logger`, this only sets `RUST_LOG` when it isn't already present in the
+/// environment, so explicit env configuration always wins over the programmatic default. Unlike
+/// `init_env_logger`, the filter can carry per-target overrides (see `LogConfig::target`), and
+/// timestamp/module-path display can be toggled independently of the filter itself.
+pub fn init_env_logger_with(config: LogConfig) {
+  if env::var(ENV_RUST_LOG)
+    .as_ref()
+    .ok()
+    .is_none()
+  {
+    let directive = config.to_directive();
+    println!("Setting logging filter to \"{}\", override with env var {}.",
+             directive, ENV_RUST_LOG);
+    env::set_var(ENV_RUST_LOG, directive);
+  }
+
+  let mut builder = env_logger::Builder::from_default_env();
+  builder.format_module_path(config.show_module_path);
+  if !config.show_timestamps {
+    builder.format_timestamp(None);
+  }
+  builder.init();
+}
+
 /// Initialize dotenv with the default `.env` config file.
 pub fn init_dotenv() {
   match dotenv::dotenv() {
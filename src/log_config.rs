@@ -0,0 +1,71 @@
+/// Programmatic, per-target log filter configuration for `init_env_logger_with`.
+///
+/// Accumulates a default level plus per-target overrides and serializes them into the
+/// `tokio_reactor=error,hyper=warn,info` directive syntax that `env_logger` understands, e.g.:
+///
+/// ```ignore
+/// let config = LogConfig::new()
+///   .default("info")
+///   .target("tokio_reactor", "error")
+///   .target("hyper", "warn");
+/// init_env_logger_with(config);
+/// ```
+pub struct LogConfig {
+  pub(crate) default_level: String,
+  pub(crate) targets: Vec<(String, String)>,
+  pub(crate) show_timestamps: bool,
+  pub(crate) show_module_path: bool,
+}
+
+impl LogConfig {
+  /// Start a new config with the crate's usual default level (`"info"`), timestamps, and
+  /// module paths all shown.
+  pub fn new() -> LogConfig {
+    LogConfig {
+      default_level: "info".to_string(),
+      targets: Vec::new(),
+      show_timestamps: true,
+      show_module_path: true,
+    }
+  }
+
+  /// Set the blanket level applied to any target without its own override.
+  pub fn default(mut self, level: &str) -> LogConfig {
+    self.default_level = level.to_string();
+    self
+  }
+
+  /// Override the level for a specific target, e.g. a crate or module name.
+  pub fn target(mut self, target: &str, level: &str) -> LogConfig {
+    self.targets.push((target.to_string(), level.to_string()));
+    self
+  }
+
+  /// Toggle whether log lines include a timestamp. Defaults to `true`.
+  pub fn show_timestamps(mut self, show: bool) -> LogConfig {
+    self.show_timestamps = show;
+    self
+  }
+
+  /// Toggle whether log lines include the emitting module's path. Defaults to `true`.
+  pub fn show_module_path(mut self, show: bool) -> LogConfig {
+    self.show_module_path = show;
+    self
+  }
+
+  /// Serialize this config into the `env_logger` directive syntax, e.g.
+  /// `"tokio_reactor=error,hyper=warn,info"`.
+  pub(crate) fn to_directive(&self) -> String {
+    let mut parts: Vec<String> = self.targets.iter()
+      .map(|(target, level)| format!("{}={}", target, level))
+      .collect();
+    parts.push(self.default_level.clone());
+    parts.join(",")
+  }
+}
+
+impl Default for LogConfig {
+  fn default() -> LogConfig {
+    LogConfig::new()
+  }
+}
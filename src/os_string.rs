@@ -0,0 +1,43 @@
+use crate::EnvError;
+use log::warn;
+use std::env;
+use std::ffi::OsString;
+
+/// Get an environment variable as an `OsString`, or return an error.
+/// Unlike the `String` accessors, this reads via `env::var_os` and never fails on non-unicode
+/// values.
+pub fn get_env_os_string_required(env_name: &str) -> Result<OsString, EnvError> {
+  match env::var_os(env_name) {
+    Some(val) => Ok(val),
+    None => {
+      warn!("Required env var '{}' not supplied.", env_name);
+      Err(EnvError::RequiredNotPresent)
+    },
+  }
+}
+
+/// Get an environment variable as an optional `OsString`.
+/// Unlike the `String` accessors, this reads via `env::var_os` and never fails on non-unicode
+/// values.
+pub fn get_env_os_string_optional(env_name: &str) -> Option<OsString> {
+  match env::var_os(env_name) {
+    Some(val) => Some(val),
+    None => {
+      warn!("Env var '{}' not supplied.", env_name);
+      None
+    },
+  }
+}
+
+/// Get an environment variable as an `OsString`, or fall back to the provided default.
+/// Unlike the `String` accessors, this reads via `env::var_os` and never fails on non-unicode
+/// values.
+pub fn get_env_os_string_or_default(env_name: &str, default: OsString) -> OsString {
+  match env::var_os(env_name) {
+    Some(val) => val,
+    None => {
+      warn!("Env var '{}' not supplied. Using default '{:?}'.", env_name, &default);
+      default
+    },
+  }
+}
@@ -52,6 +52,48 @@ pub fn get_env_pathbuf_or_default(env_name: &str, default_value: PathBuf) -> Pat
     })
 }
 
+/// Get an environment variable as a `PathBuf`, reading via `env::var_os` so non-unicode paths
+/// are preserved rather than rejected.
+/// If not provided, return an error.
+pub fn get_env_pathbuf_os_required(env_name: &str) -> Result<PathBuf, EnvError> {
+  match get_env_pathbuf_os_internal(env_name) {
+    Some(path) => Ok(path),
+    None => {
+      warn!("Env var '{}' not supplied.", env_name);
+      Err(EnvError::RequiredNotPresent)
+    },
+  }
+}
+
+/// Get an environment variable as a `PathBuf`, reading via `env::var_os` so non-unicode paths
+/// are preserved rather than rejected.
+/// If not present, return `None`.
+pub fn get_env_pathbuf_os_optional(env_name: &str) -> Option<PathBuf> {
+  match get_env_pathbuf_os_internal(env_name) {
+    Some(path) => Some(path),
+    None => {
+      warn!("Env var '{}' not present.", env_name);
+      None
+    },
+  }
+}
+
+/// Get an environment variable as a `PathBuf`, reading via `env::var_os` so non-unicode paths
+/// are preserved rather than rejected, or fall back to the provided default.
+pub fn get_env_pathbuf_os_or_default(env_name: &str, default_value: PathBuf) -> PathBuf {
+  match get_env_pathbuf_os_internal(env_name) {
+    Some(path) => path,
+    None => {
+      warn!("Env var '{}' not supplied. Using default '{:?}'.", env_name, &default_value);
+      default_value
+    },
+  }
+}
+
+fn get_env_pathbuf_os_internal(env_name: &str) -> Option<PathBuf> {
+  env::var_os(env_name).map(PathBuf::from)
+}
+
 fn get_env_pathbuf_internal(env_name: &str) -> Result<Option<PathBuf>, EnvError> {
   match env::var(env_name).as_ref() {
     Err(err) => match err {
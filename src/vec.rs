@@ -0,0 +1,91 @@
+use crate::EnvError;
+use log::warn;
+use std::env;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// Get an environment variable as a `Vec<T>`, splitting the raw value on `delimiter` and parsing
+/// each trimmed element as `T`.
+/// If not provided or cannot parse, return an error.
+pub fn get_env_vec_required<T>(env_name: &str, delimiter: &str) -> Result<Vec<T>, EnvError>
+  where T: FromStr,
+        T::Err: Debug
+{
+  get_env_vec_internal(env_name, delimiter)
+    .and_then(|maybe| match maybe {
+      None => {
+        warn!("Env var '{}' not supplied.", env_name);
+        Err(EnvError::RequiredNotPresent)
+      },
+      Some(val) => Ok(val),
+    })
+}
+
+/// Get an environment variable as a `Vec<T>`, splitting the raw value on `delimiter` and parsing
+/// each trimmed element as `T`.
+/// If not present or there is an error in parsing, return `None`.
+pub fn get_env_vec_optional<T>(env_name: &str, delimiter: &str) -> Option<Vec<T>>
+  where T: FromStr,
+        T::Err: Debug
+{
+  match get_env_vec_internal(env_name, delimiter) {
+    Err(e) => {
+      warn!("Env var '{}': error parsing list value: `{:?}`. Returning no value.", env_name, e);
+      None
+    },
+    Ok(None) => {
+      warn!("Env var '{}' not present.", env_name);
+      None
+    },
+    Ok(Some(value)) => Some(value),
+  }
+}
+
+/// Get an environment variable as a `Vec<T>`, splitting the raw value on `delimiter` and parsing
+/// each trimmed element as `T`, or fall back to the provided default.
+/// Returns the default in the event of a parse error.
+pub fn get_env_vec_or_default<T>(env_name: &str, delimiter: &str, default: Vec<T>) -> Vec<T>
+  where T: FromStr + Debug + Clone,
+        T::Err: Debug
+{
+  get_env_vec_internal(env_name, delimiter)
+    .map(|maybe| match maybe {
+      None => {
+        warn!("Env var '{}' not supplied. Using default '{:?}'.", env_name, &default);
+        default.clone() // FIXME: Remove this extra clone
+      },
+      Some(val) => val,
+    })
+    .unwrap_or_else(|e| {
+      warn!("Env var '{}': error parsing list value: {:?}. Using default '{:?}'.",
+            env_name, e, &default);
+      default
+    })
+}
+
+fn get_env_vec_internal<T>(env_name: &str, delimiter: &str) -> Result<Option<Vec<T>>, EnvError>
+  where T: FromStr,
+        T::Err: Debug
+{
+  match env::var(env_name).as_ref().ok() {
+    None => Ok(None),
+    Some(val) => {
+      if val.is_empty() {
+        return Ok(Some(Vec::new()));
+      }
+
+      let mut result = Vec::new();
+      for (index, element) in val.split(delimiter).enumerate() {
+        let trimmed = element.trim();
+        match trimmed.parse::<T>() {
+          Ok(parsed) => result.push(parsed),
+          Err(e) => return Err(EnvError::ParseError {
+            reason: format!("Couldn't parse element {} ('{}') of '{}': {:?}", index, trimmed, env_name, e)
+          }),
+        }
+      }
+
+      Ok(Some(result))
+    },
+  }
+}